@@ -7,10 +7,16 @@ use core::fmt;
 use std::{cmp::Ordering, str};
 
 use rand::distributions::{Distribution, Standard, Uniform};
+use rand::seq::SliceRandom;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+pub mod hand;
+
 /// The cards in the pack are grouped in suits. The English pattern of French-suited
 /// cards consists of four suits: clubs (♣), diamonds (♦), hearts (♥) and spades (♠).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
 pub enum Suit {
     Clubs,
@@ -104,6 +110,7 @@ impl Distribution<Suit> for Standard {
 /// Each suit includes three court cards (face cards), King, Queen and Jack;
 /// and ten numeral cards or pip cards: from one (Ace) to ten. The card with
 /// single pip is called an 'Ace'.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Rank {
     Two = 2,
@@ -236,56 +243,178 @@ impl Distribution<Rank> for Standard {
 }
 
 /// Playing cards are grouped into suits and are distinguished by its rank.
-/// A card of each rank occurs once in each of the suits.
-/// The standard deck consists of 52 cards. In addition, commercial decks
-/// include one to six jokers, which are not implemented in this module.
+/// A card of each rank occurs once in each of the suits. The standard deck
+/// consists of 52 cards. In addition, commercial decks include one to six
+/// jokers, represented by [`Card::Joker`].
 ///
 /// ```
 /// use ispeet::deck::{Card, Rank, Suit};
 /// let rank = Rank::Seven;
 /// let suit = Suit::Hearts;
 /// let card = Card::from((rank, suit));
-/// assert_eq!(card.rank(), rank);
-/// assert_eq!(card.suit(), suit);
+/// assert_eq!(card.rank(), Some(rank));
+/// assert_eq!(card.suit(), Some(suit));
+/// assert_eq!(Card::Joker { high: true }.rank(), None);
 /// ```
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Card {
-    rank: Rank,
-    suit: Suit,
+pub enum Card {
+    /// One of the 52 standard cards: a rank in a suit.
+    Standard { rank: Rank, suit: Suit },
+    /// A wildcard with no rank or suit of its own. `high` distinguishes the
+    /// two jokers found in a commercial deck, which are otherwise identical.
+    Joker { high: bool },
 }
 
 impl Card {
-    /// Getter for rank of the card.
-    pub fn rank(&self) -> Rank {
-        self.rank
+    /// Getter for rank of the card, or `None` for a joker.
+    pub fn rank(&self) -> Option<Rank> {
+        match self {
+            Card::Standard { rank, .. } => Some(*rank),
+            Card::Joker { .. } => None,
+        }
     }
 
-    /// Getter for suit of the card.
-    pub fn suit(&self) -> Suit {
-        self.suit
+    /// Getter for suit of the card, or `None` for a joker.
+    pub fn suit(&self) -> Option<Suit> {
+        match self {
+            Card::Standard { suit, .. } => Some(*suit),
+            Card::Joker { .. } => None,
+        }
     }
 }
 
 /// Pretty printing [Card]. Normal formatting prints symbol of suit and short
 /// form of rank and alternate formatting distinguishes the card in words.
+/// A joker prints as its symbol plus an `H`/`L` marker, or as "High
+/// Joker"/"Low Joker" in words, so the two jokers never collide.
 ///
 /// ```
 /// use ispeet::deck::{Card, Suit, Rank};
 /// let card = Card::from((Suit::Hearts, Rank::Seven));
 /// assert_eq!(format!("{}", card), "♥7");
 /// assert_eq!(format!("{:#}",card), "Seven of Hearts");
+/// let joker = Card::Joker { high: true };
+/// assert_eq!(format!("{}", joker), "🃏H");
+/// assert_eq!(format!("{:#}", joker), "High Joker");
 /// ```
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if f.alternate() {
-            write!(f, "{:#} of {:#}", self.rank, self.suit)
-        } else {
-            write!(f, "{}{}", self.suit, self.rank)
+        match self {
+            Card::Standard { rank, suit } => {
+                if f.alternate() {
+                    write!(f, "{:#} of {:#}", rank, suit)
+                } else {
+                    write!(f, "{}{}", suit, rank)
+                }
+            }
+            Card::Joker { high } => {
+                if f.alternate() {
+                    let which = if *high { "High" } else { "Low" };
+                    write!(f, "{which} Joker")
+                } else {
+                    let which = if *high { "H" } else { "L" };
+                    write!(f, "🃏{which}")
+                }
+            }
         }
     }
 }
 
-/// Cards of the same suit are comparable.
+/// Parses a [Card] from either a `RankSuit` token (e.g. `"7H"`), the
+/// `Display` symbol order (e.g. `"♥7"`), or the alternate words form (e.g.
+/// `"Seven of Hearts"`). Jokers parse from `"🃏H"`/`"🃏L"` or
+/// `"High Joker"`/`"Low Joker"`, case-insensitively for the words forms.
+///
+/// ```
+/// use ispeet::deck::{Card, Rank, Suit};
+/// let card: Card = "7H".parse().unwrap();
+/// assert_eq!(card, Card::from((Suit::Hearts, Rank::Seven)));
+/// let same: Card = "♥7".parse().unwrap();
+/// assert_eq!(same, card);
+/// let words: Card = "Seven of Hearts".parse().unwrap();
+/// assert_eq!(words, card);
+/// let joker: Card = "High Joker".parse().unwrap();
+/// assert_eq!(joker, Card::Joker { high: true });
+/// assert!("not a card".parse::<Card>().is_err());
+/// ```
+impl str::FromStr for Card {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if let Some(joker) = parse_joker(trimmed) {
+            return Ok(joker);
+        }
+        if let Some((rank_str, suit_str)) = split_words_form(trimmed) {
+            let rank: Rank = rank_str.parse()?;
+            let suit: Suit = suit_str.parse()?;
+            return Ok(Card::Standard { rank, suit });
+        }
+        split_compact_form(trimmed, true)
+            .or_else(|| split_compact_form(trimmed, false))
+            .ok_or_else(|| Error::ParseCard(s.to_owned()))
+    }
+}
+
+/// Recognizes the `"🃏H"`/`"🃏L"` and `"High Joker"`/`"Low Joker"` joker
+/// forms, case-insensitively for the words form.
+fn parse_joker(s: &str) -> Option<Card> {
+    match s {
+        "🃏H" => return Some(Card::Joker { high: true }),
+        "🃏L" => return Some(Card::Joker { high: false }),
+        _ => {}
+    }
+    match s.to_lowercase().as_str() {
+        "high joker" => Some(Card::Joker { high: true }),
+        "low joker" => Some(Card::Joker { high: false }),
+        _ => None,
+    }
+}
+
+/// Splits the alternate `"Rank of Suit"` words form, e.g. `"Seven of Hearts"`.
+/// Matches `" of "` ASCII-case-insensitively directly over `s`'s bytes
+/// (rather than searching a lowercased copy) so the split index always
+/// lands on an ASCII space and is a valid char boundary in `s`, even when
+/// some other character in `s` changes byte length under `to_lowercase`.
+fn split_words_form(s: &str) -> Option<(&str, &str)> {
+    let index = s
+        .as_bytes()
+        .windows(4)
+        .position(|w| w[0] == b' ' && w[1].eq_ignore_ascii_case(&b'o') && w[2].eq_ignore_ascii_case(&b'f') && w[3] == b' ')?;
+    Some((&s[..index], &s[index + 4..]))
+}
+
+/// Splits a compact card token into its rank and suit tokens, taking the
+/// suit as the leading or the trailing character, and parses them.
+/// `suit_first` selects the `Display` symbol order (`"♥7"`) versus the
+/// `RankSuit` order (`"7H"`).
+fn split_compact_form(s: &str, suit_first: bool) -> Option<Card> {
+    let mut chars = s.chars();
+    let (suit_token, rank_token) = if suit_first {
+        let suit_token = chars.next()?;
+        (suit_token.to_string(), chars.as_str())
+    } else {
+        let suit_token = chars.next_back()?;
+        (suit_token.to_string(), chars.as_str())
+    };
+    let suit: Suit = suit_token.parse().ok()?;
+    let rank: Rank = rank_token.parse().ok()?;
+    Some(Card::Standard { rank, suit })
+}
+
+/// Parses a whitespace-delimited hand of cards, e.g. `"3S 4S 5D 6H JH"`.
+///
+/// ```
+/// use ispeet::deck::{parse_hand, Card, Rank, Suit};
+/// let hand = parse_hand("3S 4S 5D 6H JH").unwrap();
+/// assert_eq!(hand[0], Card::from((Suit::Spades, Rank::Three)));
+/// assert_eq!(hand.len(), 5);
+/// ```
+pub fn parse_hand(s: &str) -> Result<Vec<Card>, Error> {
+    s.split_whitespace().map(str::parse).collect()
+}
+
+/// Standard cards of the same suit are comparable; anything involving a
+/// joker is incomparable.
 ///
 /// ```
 /// use ispeet::deck::{Card, Suit, Rank};
@@ -303,45 +432,292 @@ impl fmt::Display for Card {
 /// ```
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let ord = if self.suit == other.suit {
-            self.rank.cmp(&other.rank)
-        } else {
-            None?
-        };
-        Some(ord)
+        match (self, other) {
+            (Card::Standard { rank, suit }, Card::Standard { rank: other_rank, suit: other_suit })
+                if suit == other_suit =>
+            {
+                Some(rank.cmp(other_rank))
+            }
+            _ => None,
+        }
     }
 }
 
 /// New card construction.
 impl From<(Rank, Suit)> for Card {
     fn from((rank, suit): (Rank, Suit)) -> Self {
-        Card { rank, suit }
+        Card::Standard { rank, suit }
     }
 }
 
 /// Ease of card construction.
 impl From<(Suit, Rank)> for Card {
     fn from((suit, rank): (Suit, Rank)) -> Self {
-        Card { rank, suit }
+        Card::Standard { rank, suit }
     }
 }
 
-/// Random sampling for [Card].
+/// Random sampling for [Card]. Always samples a standard card, never a
+/// joker.
 ///
 /// ```
 /// use ispeet::deck::{Rank, Suit, Card};
 /// use rand;
 /// let card: Card = rand::random();
 /// let rank:Rank = rand::random();
-/// assert!(Rank::ALL.into_iter().any(|v| v == card.rank()));
-/// assert!(Suit::ALL.into_iter().any(|v| v == card.suit()));
+/// assert!(Rank::ALL.into_iter().any(|v| Some(v) == card.rank()));
+/// assert!(Suit::ALL.into_iter().any(|v| Some(v) == card.suit()));
 /// println!("{:?}", card);
 /// ```
 impl Distribution<Card> for Standard {
     fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Card {
         let rank: Rank = rng.gen();
         let suit: Suit = rng.gen();
-        Card { rank, suit }
+        Card::Standard { rank, suit }
+    }
+}
+
+/// Serializes a [`Card`] as its compact [`Display`](fmt::Display) string
+/// (e.g. `"♥7"`) rather than as a struct of two enums, so hands round-trip
+/// through JSON and other formats in a human-readable form.
+///
+/// ```
+/// use ispeet::deck::{Card, Rank, Suit};
+/// let card = Card::from((Suit::Hearts, Rank::Seven));
+/// assert_eq!(serde_json::to_string(&card).unwrap(), "\"♥7\"");
+/// let back: Card = serde_json::from_str("\"♥7\"").unwrap();
+/// assert_eq!(back, card);
+/// ```
+#[cfg(feature = "serde")]
+impl Serialize for Card {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Cactus Kev prime assigned to each rank. Multiplying the primes of a
+/// 5-card hand collapses hands with the same rank composition (but
+/// different suits) to the same product, which is the basis of the
+/// binary card encoding.
+fn rank_prime(rank: Rank) -> u32 {
+    match rank {
+        Two => 2,
+        Three => 3,
+        Four => 5,
+        Five => 7,
+        Six => 11,
+        Seven => 13,
+        Eight => 17,
+        Nine => 19,
+        Ten => 23,
+        Jack => 29,
+        Queen => 31,
+        King => 37,
+        Ace => 41,
+    }
+}
+
+/// 0-based rank index (`Two` = 0 ... `Ace` = 12) used in the binary card
+/// encoding.
+fn rank_index(rank: Rank) -> u32 {
+    rank as u32 - Two as u32
+}
+
+/// Inverse of [`rank_index`].
+fn rank_from_index(index: u32) -> Option<Rank> {
+    Rank::ALL.into_iter().find(|&rank| rank_index(rank) == index)
+}
+
+/// 0-based suit index used as the position of the one-hot suit flag in
+/// the binary card encoding.
+fn suit_index(suit: Suit) -> u32 {
+    Suit::ALL
+        .into_iter()
+        .position(|s| s == suit)
+        .expect("suit is always one of Suit::ALL") as u32
+}
+
+/// Inverse of [`suit_index`].
+fn suit_from_index(index: u32) -> Option<Suit> {
+    Suit::ALL.into_iter().find(|&suit| suit_index(suit) == index)
+}
+
+impl Card {
+    /// Encodes the card in the Cactus Kev 32-bit layout used for fast hand
+    /// evaluation: bits 0-5 hold the rank's prime, bits 8-11 the 0-based
+    /// rank index, bits 12-15 a one-hot suit flag, and bits 16-28 a
+    /// one-hot rank flag. This lets a batch of 5-card hands be ranked with
+    /// integer bitwise operations instead of sorting. Returns `None` for a
+    /// joker, which the encoding has no room to represent.
+    ///
+    /// ```
+    /// use ispeet::deck::{Card, Rank, Suit};
+    /// let card = Card::from((Suit::Hearts, Rank::Seven));
+    /// assert_eq!(card.to_binary(), Some(0x0020_450D));
+    /// assert_eq!(Card::Joker { high: true }.to_binary(), None);
+    /// ```
+    pub fn to_binary(&self) -> Option<u32> {
+        let Card::Standard { rank, suit } = self else {
+            return None;
+        };
+        let index = rank_index(*rank);
+        let rank_bit = 1 << (16 + index);
+        let suit_bit = 1 << (12 + suit_index(*suit));
+        let prime = rank_prime(*rank);
+        Some(rank_bit | suit_bit | (index << 8) | prime)
+    }
+
+    /// Decodes a card from its [`to_binary`](Card::to_binary) encoding,
+    /// validating that the prime, rank index, rank flag and suit flag all
+    /// agree with each other.
+    ///
+    /// ```
+    /// use ispeet::deck::{Card, Rank, Suit};
+    /// let card = Card::from((Suit::Hearts, Rank::Seven));
+    /// assert_eq!(Card::from_binary(card.to_binary().unwrap()), Ok(card));
+    /// ```
+    pub fn from_binary(bits: u32) -> Result<Card, Error> {
+        let index = (bits >> 8) & 0xF;
+        let prime = bits & 0x3F;
+        let rank = rank_from_index(index).ok_or(Error::InvalidBinary(bits))?;
+        if rank_prime(rank) != prime {
+            return Err(Error::InvalidBinary(bits));
+        }
+
+        let suit_flag = (bits >> 12) & 0xF;
+        if suit_flag.count_ones() != 1 {
+            return Err(Error::InvalidBinary(bits));
+        }
+        let suit =
+            suit_from_index(suit_flag.trailing_zeros()).ok_or(Error::InvalidBinary(bits))?;
+
+        let rank_flag = (bits >> 16) & 0x1FFF;
+        if rank_flag != 1 << index {
+            return Err(Error::InvalidBinary(bits));
+        }
+
+        Ok(Card::Standard { rank, suit })
+    }
+}
+
+/// A pack of cards, ordered from bottom to top. Cards are drawn and dealt
+/// from the top of the deck.
+///
+/// ```
+/// use ispeet::deck::Deck;
+/// let deck = Deck::standard();
+/// assert_eq!(deck.len(), 52);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// Builds a standard 52-card deck, one of each rank in each suit, in a
+    /// fixed (unshuffled) order.
+    ///
+    /// ```
+    /// use ispeet::deck::Deck;
+    /// let deck = Deck::standard();
+    /// assert_eq!(deck.len(), 52);
+    /// assert!(!deck.is_empty());
+    /// ```
+    pub fn standard() -> Self {
+        let cards = Suit::ALL
+            .into_iter()
+            .flat_map(|suit| Rank::ALL.into_iter().map(move |rank| Card::from((suit, rank))))
+            .collect();
+        Deck { cards }
+    }
+
+    /// Builds a standard 52-card deck plus `n` jokers on top, alternating
+    /// [`Card::Joker`]'s `high` flag starting with `true`.
+    ///
+    /// ```
+    /// use ispeet::deck::Deck;
+    /// let deck = Deck::with_jokers(2);
+    /// assert_eq!(deck.len(), 54);
+    /// ```
+    pub fn with_jokers(n: usize) -> Self {
+        let mut deck = Self::standard();
+        deck.cards
+            .extend((0..n).map(|i| Card::Joker { high: i % 2 == 0 }));
+        deck
+    }
+
+    /// Randomly reorders the cards remaining in the deck.
+    ///
+    /// ```
+    /// use ispeet::deck::Deck;
+    /// let mut deck = Deck::standard();
+    /// deck.shuffle(&mut rand::thread_rng());
+    /// assert_eq!(deck.len(), 52);
+    /// ```
+    pub fn shuffle<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Removes and returns a single card from the top of the deck, if any
+    /// remain.
+    ///
+    /// ```
+    /// use ispeet::deck::Deck;
+    /// let mut deck = Deck::standard();
+    /// let card = deck.draw_one();
+    /// assert!(card.is_some());
+    /// assert_eq!(deck.len(), 51);
+    /// ```
+    pub fn draw_one(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Removes and returns up to `n` cards from the top of the deck, in the
+    /// order they were dealt. Returns fewer than `n` cards once the deck
+    /// runs out.
+    ///
+    /// ```
+    /// use ispeet::deck::Deck;
+    /// let mut deck = Deck::standard();
+    /// let hand = deck.deal(5);
+    /// assert_eq!(hand.len(), 5);
+    /// assert_eq!(deck.len(), 47);
+    /// ```
+    pub fn deal(&mut self, n: usize) -> Vec<Card> {
+        let n = n.min(self.cards.len());
+        (0..n).filter_map(|_| self.draw_one()).collect()
+    }
+
+    /// Cuts the deck: the top `at` cards are moved to the bottom, as if the
+    /// deck were physically split there and the two halves swapped.
+    ///
+    /// ```
+    /// use ispeet::deck::Deck;
+    /// let mut deck = Deck::standard();
+    /// deck.cut(26);
+    /// assert_eq!(deck.len(), 52);
+    /// ```
+    pub fn cut(&mut self, at: usize) {
+        let at = at.min(self.cards.len());
+        self.cards.rotate_right(at);
+    }
+
+    /// Number of cards remaining in the deck.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Reports whether the deck has no cards left.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
     }
 }
 
@@ -362,6 +738,10 @@ pub enum Error {
     ParseSuit(String),
     #[error("cannot parse {0:?} into Rank")]
     ParseRank(String),
+    #[error("{0:#010x} is not a valid binary card encoding")]
+    InvalidBinary(u32),
+    #[error("cannot parse {0:?} into Card")]
+    ParseCard(String),
 }
 
 #[cfg(test)]
@@ -443,4 +823,238 @@ mod test {
     fn ace_is_not_face() {
         assert!(!Ace.face_card());
     }
+
+    #[test]
+    fn standard_deck_has_52_unique_cards() {
+        let deck = Deck::standard();
+        let unique: HashSet<_> = deck.cards.iter().collect();
+        assert_eq!(deck.len(), 52);
+        assert_eq!(unique.len(), 52);
+    }
+
+    #[test]
+    fn deal_and_draw_shrink_the_deck() {
+        let mut deck = Deck::standard();
+        let hand = deck.deal(5);
+        assert_eq!(hand.len(), 5);
+        assert_eq!(deck.len(), 47);
+        let card = deck.draw_one();
+        assert!(card.is_some());
+        assert_eq!(deck.len(), 46);
+    }
+
+    #[test]
+    fn deal_more_than_remaining_returns_what_is_left() {
+        let mut deck = Deck::standard();
+        let hand = deck.deal(100);
+        assert_eq!(hand.len(), 52);
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn cut_preserves_cards_and_length() {
+        let mut deck = Deck::standard();
+        let before: HashSet<_> = deck.cards.iter().cloned().collect();
+        deck.cut(26);
+        let after: HashSet<_> = deck.cards.iter().cloned().collect();
+        assert_eq!(deck.len(), 52);
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn binary_encoding_round_trips_for_every_card() {
+        for suit in Suit::ALL {
+            for rank in Rank::ALL {
+                let card = Card::from((suit, rank));
+                let decoded = Card::from_binary(card.to_binary().unwrap()).unwrap();
+                assert_eq!(decoded, card);
+            }
+        }
+    }
+
+    #[test]
+    fn binary_encoding_flush_detection() {
+        let hand = [
+            Card::from((Spades, Two)),
+            Card::from((Spades, Five)),
+            Card::from((Spades, Nine)),
+            Card::from((Spades, Jack)),
+            Card::from((Spades, King)),
+        ];
+        let suit_bits = hand
+            .iter()
+            .map(|card| card.to_binary().unwrap() >> 12 & 0xF)
+            .fold(0xF, |acc, bits| acc & bits);
+        assert_ne!(suit_bits, 0);
+
+        let mixed = [
+            Card::from((Spades, Two)),
+            Card::from((Hearts, Five)),
+            Card::from((Spades, Nine)),
+            Card::from((Spades, Jack)),
+            Card::from((Spades, King)),
+        ];
+        let suit_bits = mixed
+            .iter()
+            .map(|card| card.to_binary().unwrap() >> 12 & 0xF)
+            .fold(0xF, |acc, bits| acc & bits);
+        assert_eq!(suit_bits, 0);
+    }
+
+    #[test]
+    fn from_binary_rejects_inconsistent_fields() {
+        let card = Card::from((Hearts, Seven));
+        let tampered = card.to_binary().unwrap() ^ 1; // flip a prime bit
+        assert_eq!(
+            Card::from_binary(tampered),
+            Err(Error::InvalidBinary(tampered))
+        );
+    }
+
+    #[test]
+    fn with_jokers_adds_to_a_standard_deck() {
+        let deck = Deck::with_jokers(2);
+        assert_eq!(deck.len(), 54);
+        let jokers = deck
+            .cards
+            .iter()
+            .filter(|card| matches!(card, Card::Joker { .. }))
+            .count();
+        assert_eq!(jokers, 2);
+    }
+
+    #[test]
+    fn joker_has_no_rank_or_suit() {
+        let joker = Card::Joker { high: true };
+        assert_eq!(joker.rank(), None);
+        assert_eq!(joker.suit(), None);
+    }
+
+    #[test]
+    fn joker_display() {
+        let joker = Card::Joker { high: true };
+        assert_eq!(format!("{joker}"), "🃏H");
+        assert_eq!(format!("{joker:#}"), "High Joker");
+        assert_eq!(format!("{:#}", Card::Joker { high: false }), "Low Joker");
+        assert_eq!(format!("{}", Card::Joker { high: false }), "🃏L");
+    }
+
+    #[test]
+    fn joker_is_incomparable() {
+        let joker = Card::Joker { high: true };
+        let card = Card::from((Hearts, Seven));
+        assert_eq!(joker.partial_cmp(&card), None);
+        assert_eq!(joker.partial_cmp(&joker), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn suit_and_rank_serialize_as_their_debug_variant_name() {
+        assert_eq!(serde_json::to_string(&Spades).unwrap(), "\"Spades\"");
+        assert_eq!(serde_json::to_string(&Seven).unwrap(), "\"Seven\"");
+        assert_eq!(
+            serde_json::from_str::<Suit>("\"Spades\"").unwrap(),
+            Spades
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn card_serializes_as_its_display_string() {
+        let card = Card::from((Hearts, Seven));
+        assert_eq!(serde_json::to_string(&card).unwrap(), "\"♥7\"");
+        let back: Card = serde_json::from_str("\"♥7\"").unwrap();
+        assert_eq!(back, card);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn joker_round_trips_through_json() {
+        let joker = Card::Joker { high: false };
+        let json = serde_json::to_string(&joker).unwrap();
+        assert_eq!(json, "\"🃏L\"");
+        assert_eq!(serde_json::from_str::<Card>(&json).unwrap(), joker);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializing_a_malformed_card_fails() {
+        assert!(serde_json::from_str::<Card>("\"not a card\"").is_err());
+    }
+
+    #[test]
+    fn card_parses_from_rank_suit_order() {
+        let card: Card = "7H".parse().unwrap();
+        assert_eq!(card, Card::from((Hearts, Seven)));
+        let ten: Card = "10S".parse().unwrap();
+        assert_eq!(ten, Card::from((Spades, Ten)));
+    }
+
+    #[test]
+    fn card_parses_from_display_symbol_order() {
+        let card: Card = "♥7".parse().unwrap();
+        assert_eq!(card, Card::from((Hearts, Seven)));
+    }
+
+    #[test]
+    fn card_parses_from_words_form() {
+        let card: Card = "Seven of Hearts".parse().unwrap();
+        assert_eq!(card, Card::from((Hearts, Seven)));
+        let lower: Card = "seven of hearts".parse().unwrap();
+        assert_eq!(lower, card);
+    }
+
+    #[test]
+    fn joker_parses_from_symbol_and_words() {
+        assert_eq!(
+            "🃏H".parse::<Card>().unwrap(),
+            Card::Joker { high: true }
+        );
+        assert_eq!(
+            "Low Joker".parse::<Card>().unwrap(),
+            Card::Joker { high: false }
+        );
+    }
+
+    #[test]
+    fn malformed_card_string_fails_to_parse() {
+        assert_eq!(
+            "not a card".parse::<Card>(),
+            Err(Error::ParseCard("not a card".to_owned()))
+        );
+    }
+
+    #[test]
+    fn malformed_card_with_multi_byte_lowercasing_character_fails_without_panicking() {
+        // 'ẞ' (U+1E9E, 3 bytes) lowercases to 'ß' (U+00DF, 2 bytes), which
+        // used to shift the " of " split index off a char boundary and
+        // panic instead of returning an error.
+        assert_eq!(
+            "ẞ of Hearts".parse::<Card>(),
+            Err(Error::ParseRank("ẞ".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parse_hand_splits_on_whitespace() {
+        let hand = parse_hand("3S 4S 5D 6H JH").unwrap();
+        assert_eq!(
+            hand,
+            vec![
+                Card::from((Spades, Three)),
+                Card::from((Spades, Four)),
+                Card::from((Diamonds, Five)),
+                Card::from((Hearts, Six)),
+                Card::from((Hearts, Jack)),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_hand_rejects_a_malformed_card() {
+        assert_eq!(
+            parse_hand("3S XX"),
+            Err(Error::ParseCard("XX".to_owned()))
+        );
+    }
 }
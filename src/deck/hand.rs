@@ -0,0 +1,492 @@
+//! Classification and comparison of 5-card poker hands.
+
+use std::collections::HashMap;
+
+use super::{Card, Rank};
+use Rank::*;
+
+/// The classification of a 5-card poker hand.
+///
+/// Variants carry the ranks that matter for breaking ties within their
+/// own category, most significant first, so the derived [`Ord`] gives a
+/// total order: hands are compared by category first (in the order the
+/// variants are declared below) and, for two hands in the same category,
+/// by those ranks.
+///
+/// The counting-based categories (`OnePair` through `FourOfAKind`) carry a
+/// trailing `bool`, `true` unless the hand's most significant rank was
+/// completed by a [`JokerRule::LowJoker`] wildcard. It is compared last, so
+/// it only breaks ties between two hands that already share every rank —
+/// it never overrides the real rank data used to order hands of different
+/// ranks within the same category. See [`evaluate_with_jokers`].
+///
+/// ```
+/// use ispeet::deck::Rank;
+/// use ispeet::deck::hand::HandRank;
+/// assert!(HandRank::OnePair([Rank::Two, Rank::Ace, Rank::King, Rank::Queen], true)
+///     < HandRank::TwoPair([Rank::Three, Rank::Two, Rank::Ace], true));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum HandRank {
+    /// Five cards that share no category, ranked high to low.
+    HighCard([Rank; 5]),
+    /// One pair, then the three remaining kickers high to low, then
+    /// whether the pair is natural (see the type docs).
+    OnePair([Rank; 4], bool),
+    /// The higher pair, the lower pair, then the kicker, then whether the
+    /// higher pair is natural (see the type docs).
+    TwoPair([Rank; 3], bool),
+    /// Three of a kind, then the two remaining kickers high to low, then
+    /// whether the triple is natural (see the type docs).
+    ThreeOfAKind([Rank; 3], bool),
+    /// Five ranks in sequence; holds the high card of the run (`Five` for
+    /// the A-2-3-4-5 "wheel").
+    Straight(Rank),
+    /// Five cards of one suit, ranked high to low.
+    Flush([Rank; 5]),
+    /// Three of a kind plus a pair: the triple's rank, then the pair's,
+    /// then whether the triple is natural (see the type docs).
+    FullHouse([Rank; 2], bool),
+    /// Four of a kind, then the kicker, then whether the quad is natural
+    /// (see the type docs).
+    FourOfAKind([Rank; 2], bool),
+    /// A straight that is also a flush; holds the high card of the run.
+    StraightFlush(Rank),
+    /// The Ace-high straight flush.
+    RoyalFlush,
+}
+
+/// Classifies a 5-card hand, determining its [`HandRank`].
+///
+/// Panics if `cards` contains a [`Card::Joker`]; use
+/// [`evaluate_with_jokers`] for hands that may include one.
+///
+/// ```
+/// use ispeet::deck::{Card, Rank, Suit};
+/// use ispeet::deck::hand::{evaluate, HandRank};
+/// let wheel = [
+///     Card::from((Suit::Spades, Rank::Ace)),
+///     Card::from((Suit::Spades, Rank::Two)),
+///     Card::from((Suit::Diamonds, Rank::Three)),
+///     Card::from((Suit::Clubs, Rank::Four)),
+///     Card::from((Suit::Hearts, Rank::Five)),
+/// ];
+/// assert_eq!(evaluate(&wheel), HandRank::Straight(Rank::Five));
+/// ```
+pub fn evaluate(cards: &[Card; 5]) -> HandRank {
+    let mut ranks: Vec<Rank> = cards.iter().map(expect_rank).collect();
+    ranks.sort_by(|a, b| b.cmp(a));
+    let suits: Vec<_> = cards.iter().map(expect_suit).collect();
+    let flush = suits.iter().all(|suit| *suit == suits[0]);
+    let straight_high = straight_high(&ranks);
+    let groups = rank_groups(&ranks);
+    let counts: Vec<u8> = groups.iter().map(|(_, count)| *count).collect();
+
+    if flush {
+        match straight_high {
+            Some(Ace) => return HandRank::RoyalFlush,
+            Some(high) => return HandRank::StraightFlush(high),
+            None => {}
+        }
+    }
+    match counts.as_slice() {
+        [4, 1] => return HandRank::FourOfAKind([groups[0].0, groups[1].0], true),
+        [3, 2] => return HandRank::FullHouse([groups[0].0, groups[1].0], true),
+        _ => {}
+    }
+    if flush {
+        return HandRank::Flush(to_array(&ranks));
+    }
+    if let Some(high) = straight_high {
+        return HandRank::Straight(high);
+    }
+    match counts.as_slice() {
+        [3, 1, 1] => HandRank::ThreeOfAKind([groups[0].0, groups[1].0, groups[2].0], true),
+        [2, 2, 1] => HandRank::TwoPair([groups[0].0, groups[1].0, groups[2].0], true),
+        [2, 1, 1, 1] => {
+            HandRank::OnePair([groups[0].0, groups[1].0, groups[2].0, groups[3].0], true)
+        }
+        _ => HandRank::HighCard(to_array(&ranks)),
+    }
+}
+
+/// Groups ranks by how often they occur, sorted by count (descending) and
+/// then by rank (descending) to break ties between groups of equal size.
+fn rank_groups(ranks: &[Rank]) -> Vec<(Rank, u8)> {
+    let mut counts: HashMap<Rank, u8> = HashMap::new();
+    for &rank in ranks {
+        *counts.entry(rank).or_insert(0) += 1;
+    }
+    let mut groups: Vec<(Rank, u8)> = counts.into_iter().collect();
+    groups.sort_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+    groups
+}
+
+/// Reports the high card of a straight formed by `ranks`, treating Ace as
+/// low for the A-2-3-4-5 "wheel". `ranks` need not be sorted or unique.
+fn straight_high(ranks: &[Rank]) -> Option<Rank> {
+    let mut uniq: Vec<Rank> = ranks.to_vec();
+    uniq.sort();
+    uniq.dedup();
+    if uniq.len() != 5 {
+        return None;
+    }
+    if uniq == [Two, Three, Four, Five, Ace] {
+        return Some(Five);
+    }
+    let consecutive = uniq.windows(2).all(|pair| pair[1] as i8 - pair[0] as i8 == 1);
+    consecutive.then(|| uniq[4])
+}
+
+fn to_array(ranks: &[Rank]) -> [Rank; 5] {
+    ranks.try_into().expect("a hand always has exactly 5 cards")
+}
+
+fn expect_rank(card: &Card) -> Rank {
+    card.rank()
+        .expect("evaluate() does not support jokers; use evaluate_with_jokers()")
+}
+
+fn expect_suit(card: &Card) -> super::Suit {
+    card.suit()
+        .expect("evaluate() does not support jokers; use evaluate_with_jokers()")
+}
+
+/// Controls how a joker's wildcard slot compares when breaking ties
+/// between two hands in the same [`HandRank`] category. A joker always
+/// completes the best available counting-based category (see
+/// [`evaluate_with_jokers`]);
+/// this only affects kicker comparisons within that category.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum JokerRule {
+    /// The joker's assigned rank counts like a natural card of that rank.
+    Natural,
+    /// The joker's assigned rank still counts for ordering, but a hand
+    /// completed with a joker loses ties to an otherwise identical hand
+    /// made entirely of natural cards.
+    LowJoker,
+}
+
+/// Classifies a 5-card hand that may include jokers, determining its
+/// [`HandRank`]. Each joker is folded into whichever natural rank already
+/// has the highest count before classification (the same counting trick
+/// used by Camel-Cards-style wildcards), so a joker always completes the
+/// best available counting-based hand; `rule` only controls how its slot compares when
+/// breaking ties within that category.
+///
+/// Jokers only complete the counting-based categories (one pair through
+/// four of a kind): a hand containing a joker is never classified as a
+/// straight, flush, straight flush or royal flush.
+///
+/// ```
+/// use ispeet::deck::{Card, Rank, Suit};
+/// use ispeet::deck::hand::{evaluate_with_jokers, HandRank, JokerRule};
+/// let hand = [
+///     Card::from((Suit::Clubs, Rank::Nine)),
+///     Card::from((Suit::Diamonds, Rank::Nine)),
+///     Card::Joker { high: true },
+///     Card::from((Suit::Hearts, Rank::Two)),
+///     Card::from((Suit::Spades, Rank::Four)),
+/// ];
+/// assert_eq!(
+///     evaluate_with_jokers(&hand, JokerRule::Natural),
+///     HandRank::ThreeOfAKind([Rank::Nine, Rank::Four, Rank::Two], true),
+/// );
+/// ```
+pub fn evaluate_with_jokers(cards: &[Card; 5], rule: JokerRule) -> HandRank {
+    let jokers = cards
+        .iter()
+        .filter(|card| matches!(card, Card::Joker { .. }))
+        .count();
+    if jokers == 0 {
+        return evaluate(cards);
+    }
+
+    let naturals: Vec<Rank> = cards.iter().filter_map(Card::rank).collect();
+    let mut groups = rank_groups(&naturals);
+    if groups.is_empty() {
+        groups.push((Ace, 0));
+    }
+    groups[0].1 += jokers as u8;
+    groups.sort_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+
+    // The group boosted by the jokers is always first: boosting the
+    // highest count can only keep it highest (or tie it, broken by rank).
+    // Its real rank is always kept (never overwritten by a sentinel), so
+    // hands of different ranks in the same category still compare
+    // correctly; `natural` is only a trailing tiebreak for two hands that
+    // already share every rank.
+    let boosted = groups[0].0;
+    let natural = !matches!(rule, JokerRule::LowJoker);
+
+    let counts: Vec<u8> = groups.iter().map(|(_, count)| *count).collect();
+    match counts.as_slice() {
+        // Five of a kind has no HandRank of its own; the closest category
+        // we can express is four of a kind with itself as the kicker.
+        [5] => HandRank::FourOfAKind([boosted, boosted], natural),
+        [4, 1] => HandRank::FourOfAKind([boosted, groups[1].0], natural),
+        [3, 2] => HandRank::FullHouse([boosted, groups[1].0], natural),
+        [3, 1, 1] => HandRank::ThreeOfAKind([boosted, groups[1].0, groups[2].0], natural),
+        [2, 2, 1] => HandRank::TwoPair([boosted, groups[1].0, groups[2].0], natural),
+        [2, 1, 1, 1] => {
+            HandRank::OnePair([boosted, groups[1].0, groups[2].0, groups[3].0], natural)
+        }
+        // At most 4 groups remain once the highest is boosted by the
+        // jokers, and every partition of 5 into 4 or fewer parts is
+        // covered above.
+        _ => unreachable!("boosted rank groups always match one of the patterns above"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::deck::Suit;
+    use crate::deck::Suit::*;
+
+    fn card(suit: Suit, rank: Rank) -> Card {
+        Card::from((suit, rank))
+    }
+
+    #[test]
+    fn royal_flush() {
+        let hand = [
+            card(Spades, Ten),
+            card(Spades, Jack),
+            card(Spades, Queen),
+            card(Spades, King),
+            card(Spades, Ace),
+        ];
+        assert_eq!(evaluate(&hand), HandRank::RoyalFlush);
+    }
+
+    #[test]
+    fn straight_flush() {
+        let hand = [
+            card(Hearts, Six),
+            card(Hearts, Seven),
+            card(Hearts, Eight),
+            card(Hearts, Nine),
+            card(Hearts, Ten),
+        ];
+        assert_eq!(evaluate(&hand), HandRank::StraightFlush(Ten));
+    }
+
+    #[test]
+    fn four_of_a_kind() {
+        let hand = [
+            card(Clubs, Nine),
+            card(Diamonds, Nine),
+            card(Hearts, Nine),
+            card(Spades, Nine),
+            card(Spades, Two),
+        ];
+        assert_eq!(evaluate(&hand), HandRank::FourOfAKind([Nine, Two], true));
+    }
+
+    #[test]
+    fn full_house() {
+        let hand = [
+            card(Clubs, King),
+            card(Diamonds, King),
+            card(Hearts, King),
+            card(Spades, Four),
+            card(Clubs, Four),
+        ];
+        assert_eq!(evaluate(&hand), HandRank::FullHouse([King, Four], true));
+    }
+
+    #[test]
+    fn flush() {
+        let hand = [
+            card(Diamonds, Two),
+            card(Diamonds, Five),
+            card(Diamonds, Nine),
+            card(Diamonds, Jack),
+            card(Diamonds, King),
+        ];
+        assert_eq!(
+            evaluate(&hand),
+            HandRank::Flush([King, Jack, Nine, Five, Two])
+        );
+    }
+
+    #[test]
+    fn wheel_straight_ranks_ace_low() {
+        let hand = [
+            card(Spades, Ace),
+            card(Clubs, Two),
+            card(Diamonds, Three),
+            card(Hearts, Four),
+            card(Spades, Five),
+        ];
+        assert_eq!(evaluate(&hand), HandRank::Straight(Five));
+    }
+
+    #[test]
+    fn two_pair() {
+        let hand = [
+            card(Clubs, Eight),
+            card(Diamonds, Eight),
+            card(Hearts, Three),
+            card(Spades, Three),
+            card(Clubs, King),
+        ];
+        assert_eq!(evaluate(&hand), HandRank::TwoPair([Eight, Three, King], true));
+    }
+
+    #[test]
+    fn one_pair() {
+        let hand = [
+            card(Clubs, Ten),
+            card(Diamonds, Ten),
+            card(Hearts, Two),
+            card(Spades, Five),
+            card(Clubs, King),
+        ];
+        assert_eq!(
+            evaluate(&hand),
+            HandRank::OnePair([Ten, King, Five, Two], true)
+        );
+    }
+
+    #[test]
+    fn high_card() {
+        let hand = [
+            card(Clubs, Two),
+            card(Diamonds, Five),
+            card(Hearts, Nine),
+            card(Spades, Jack),
+            card(Clubs, King),
+        ];
+        assert_eq!(
+            evaluate(&hand),
+            HandRank::HighCard([King, Jack, Nine, Five, Two])
+        );
+    }
+
+    #[test]
+    fn categories_outrank_each_other_regardless_of_kickers() {
+        let high_straight = HandRank::Straight(Ace);
+        let low_flush = HandRank::Flush([Seven, Five, Four, Three, Two]);
+        assert!(high_straight < low_flush);
+    }
+
+    #[test]
+    fn joker_completes_three_of_a_kind() {
+        let hand = [
+            card(Clubs, Nine),
+            card(Diamonds, Nine),
+            Card::Joker { high: true },
+            card(Hearts, Two),
+            card(Spades, Four),
+        ];
+        assert_eq!(
+            evaluate_with_jokers(&hand, JokerRule::Natural),
+            HandRank::ThreeOfAKind([Nine, Four, Two], true)
+        );
+    }
+
+    #[test]
+    fn joker_never_completes_a_straight_or_flush() {
+        let hand = [
+            card(Hearts, Six),
+            card(Hearts, Seven),
+            card(Hearts, Eight),
+            card(Hearts, Nine),
+            Card::Joker { high: true },
+        ];
+        assert_eq!(
+            evaluate_with_jokers(&hand, JokerRule::Natural),
+            HandRank::OnePair([Nine, Eight, Seven, Six], true)
+        );
+    }
+
+    #[test]
+    fn low_joker_rule_loses_ties_to_natural_hands() {
+        let natural = [
+            card(Clubs, Nine),
+            card(Diamonds, Nine),
+            card(Hearts, Nine),
+            card(Spades, Four),
+            card(Clubs, Two),
+        ];
+        let with_joker = [
+            card(Clubs, Nine),
+            card(Diamonds, Nine),
+            Card::Joker { high: true },
+            card(Spades, Four),
+            card(Clubs, Two),
+        ];
+        let natural_rank = evaluate(&natural);
+        let joker_rank = evaluate_with_jokers(&with_joker, JokerRule::LowJoker);
+        assert_eq!(natural_rank.cmp(&joker_rank), std::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn low_joker_rule_still_orders_different_ranks_within_a_category() {
+        let aces = [
+            Card::Joker { high: true },
+            Card::Joker { high: false },
+            card(Clubs, Ace),
+            card(Diamonds, Ace),
+            card(Hearts, Three),
+        ];
+        let kings = [
+            Card::Joker { high: true },
+            Card::Joker { high: false },
+            card(Clubs, King),
+            card(Diamonds, King),
+            card(Hearts, Three),
+        ];
+        assert_eq!(
+            evaluate_with_jokers(&aces, JokerRule::LowJoker),
+            HandRank::FourOfAKind([Ace, Three], false)
+        );
+        assert_eq!(
+            evaluate_with_jokers(&kings, JokerRule::LowJoker),
+            HandRank::FourOfAKind([King, Three], false)
+        );
+        assert!(
+            evaluate_with_jokers(&aces, JokerRule::LowJoker)
+                > evaluate_with_jokers(&kings, JokerRule::LowJoker)
+        );
+    }
+
+    #[test]
+    fn low_joker_rule_orders_two_joker_completed_three_of_a_kinds() {
+        let nines = [
+            Card::Joker { high: true },
+            card(Clubs, Nine),
+            card(Diamonds, Nine),
+            card(Hearts, Four),
+            card(Spades, Two),
+        ];
+        let queens = [
+            Card::Joker { high: true },
+            card(Clubs, Queen),
+            card(Diamonds, Queen),
+            card(Hearts, Four),
+            card(Spades, Two),
+        ];
+        assert!(
+            evaluate_with_jokers(&queens, JokerRule::LowJoker)
+                > evaluate_with_jokers(&nines, JokerRule::LowJoker)
+        );
+    }
+
+    #[test]
+    fn five_jokers_fall_back_to_four_of_a_kind() {
+        let hand = [
+            Card::Joker { high: true },
+            Card::Joker { high: false },
+            Card::Joker { high: true },
+            Card::Joker { high: false },
+            Card::Joker { high: true },
+        ];
+        assert_eq!(
+            evaluate_with_jokers(&hand, JokerRule::Natural),
+            HandRank::FourOfAKind([Ace, Ace], true)
+        );
+    }
+}